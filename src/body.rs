@@ -0,0 +1,170 @@
+use async_std::io::{self, BufRead, Read};
+
+use std::fmt::{self, Debug};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::mime::{self, Mime};
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+type BodyReader = dyn BufRead + Unpin + Send + 'static;
+
+pin_project_lite::pin_project! {
+    /// A streaming HTTP body.
+    ///
+    /// `Body` represents the data half of an HTTP request or response, decoupled from any
+    /// particular owner so it can be created, inspected, and moved around independently.
+    pub struct Body {
+        #[pin]
+        reader: Box<BodyReader>,
+        mime: Mime,
+        length: Option<usize>,
+    }
+}
+
+impl Body {
+    /// Create a new empty body.
+    pub fn empty() -> Self {
+        Self {
+            reader: Box::new(io::empty()),
+            mime: mime::BYTE_STREAM,
+            length: Some(0),
+        }
+    }
+
+    /// Create a new body from a reader, with an optional length.
+    ///
+    /// If the length is `None`, the body will be streamed as `Transfer-Encoding: chunked`.
+    pub fn from_reader(reader: impl BufRead + Unpin + Send + 'static, len: Option<usize>) -> Self {
+        Self {
+            reader: Box::new(reader),
+            mime: mime::BYTE_STREAM,
+            length: len,
+        }
+    }
+
+    /// Create a new body from a string.
+    ///
+    /// # Mime
+    ///
+    /// The encoding is set to `text/plain; charset=utf-8`.
+    pub fn from_string(string: String) -> Self {
+        Self {
+            length: Some(string.len()),
+            reader: Box::new(io::Cursor::new(string.into_bytes())),
+            mime: mime::PLAIN,
+        }
+    }
+
+    /// Create a new body from bytes.
+    ///
+    /// # Mime
+    ///
+    /// The encoding is set to `application/octet-stream`.
+    pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Self {
+        let bytes = bytes.as_ref().to_owned();
+        Self {
+            length: Some(bytes.len()),
+            reader: Box::new(io::Cursor::new(bytes)),
+            mime: mime::BYTE_STREAM,
+        }
+    }
+
+    /// Create a new body from a JSON-serializable value.
+    ///
+    /// # Mime
+    ///
+    /// The encoding is set to `application/json`.
+    #[cfg(feature = "serde")]
+    pub fn from_json<T: Serialize>(value: &T) -> io::Result<Self> {
+        let bytes = serde_json::to_vec(value)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(Self {
+            length: Some(bytes.len()),
+            reader: Box::new(io::Cursor::new(bytes)),
+            mime: mime::JSON,
+        })
+    }
+
+    /// Create a new body from a form-serializable value.
+    ///
+    /// # Mime
+    ///
+    /// The encoding is set to `application/x-www-form-urlencoded`.
+    #[cfg(feature = "serde")]
+    pub fn from_form<T: Serialize>(value: &T) -> io::Result<Self> {
+        let string = serde_urlencoded::to_string(value)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(Self {
+            length: Some(string.len()),
+            reader: Box::new(io::Cursor::new(string.into_bytes())),
+            mime: mime::FORM_URLENCODED,
+        })
+    }
+
+    /// Get the length of the body in bytes, if it has been set.
+    ///
+    /// This value is set when passing a fixed-size object into as the body. E.g. a string, or a
+    /// buffer. Consumers of this API should check this value to decide whether to use `Chunked`
+    /// encoding, or set the content length.
+    pub fn len(&self) -> Option<usize> {
+        self.length
+    }
+
+    /// Get the MIME type of this body.
+    pub fn mime(&self) -> &Mime {
+        &self.mime
+    }
+
+    /// Set the MIME type of this body.
+    pub fn set_mime(&mut self, mime: Mime) {
+        self.mime = mime;
+    }
+}
+
+impl Debug for Body {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Body")
+            .field("reader", &"<hidden>")
+            .field("mime", &self.mime)
+            .field("length", &self.length)
+            .finish()
+    }
+}
+
+impl Read for Body {
+    #[allow(missing_doc_code_examples)]
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.reader).poll_read(cx, buf)
+    }
+}
+
+impl BufRead for Body {
+    #[allow(missing_doc_code_examples)]
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&'_ [u8]>> {
+        let this = self.project();
+        this.reader.poll_fill_buf(cx)
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amt: usize) {
+        Pin::new(&mut self.reader).consume(amt)
+    }
+}
+
+impl From<String> for Body {
+    fn from(s: String) -> Self {
+        Body::from_string(s)
+    }
+}
+
+impl From<Vec<u8>> for Body {
+    fn from(bytes: Vec<u8>) -> Self {
+        Body::from_bytes(bytes)
+    }
+}
@@ -0,0 +1,324 @@
+use async_std::io;
+
+use std::collections::hash_map::{IntoIter as MapIntoIter, Iter as MapIter, IterMut as MapIterMut};
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Display};
+use std::hash::{Hash, Hasher};
+use std::ops::Index;
+
+/// The name of an HTTP header.
+#[derive(Clone, Eq)]
+pub struct HeaderName {
+    pub(crate) string: String,
+    pub(crate) static_str: Option<&'static str>,
+}
+
+impl HeaderName {
+    /// Get the header name as a `&str`.
+    pub fn as_str(&self) -> &str {
+        self.static_str.unwrap_or(&self.string)
+    }
+}
+
+/// Header names are matched case-insensitively, per RFC 7230.
+impl PartialEq for HeaderName {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str().eq_ignore_ascii_case(other.as_str())
+    }
+}
+
+impl Hash for HeaderName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for byte in self.as_str().as_bytes() {
+            state.write_u8(byte.to_ascii_lowercase());
+        }
+    }
+}
+
+impl Debug for HeaderName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Display for HeaderName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for HeaderName {
+    fn from(name: &str) -> Self {
+        Self {
+            string: name.to_owned(),
+            static_str: None,
+        }
+    }
+}
+
+impl From<String> for HeaderName {
+    fn from(name: String) -> Self {
+        Self {
+            string: name,
+            static_str: None,
+        }
+    }
+}
+
+/// A single HTTP header value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderValue(String);
+
+impl HeaderValue {
+    /// Get the header value as a `&str`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for HeaderValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for HeaderValue {
+    fn from(value: &str) -> Self {
+        Self(value.to_owned())
+    }
+}
+
+impl From<String> for HeaderValue {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+/// A list of `HeaderValue`s for a single header name.
+///
+/// Most headers only ever carry a single value, but HTTP allows a name to repeat; `HeaderValues`
+/// keeps the full list while making the single-value case convenient.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderValues(Vec<HeaderValue>);
+
+impl HeaderValues {
+    pub(crate) fn new(values: Vec<HeaderValue>) -> Self {
+        Self(values)
+    }
+
+    /// Get the last header value.
+    pub fn last(&self) -> Option<&HeaderValue> {
+        self.0.last()
+    }
+
+    /// Get the number of header values.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check whether there are any header values.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// An iterator visiting all header values in insertion order.
+    pub fn iter(&self) -> std::slice::Iter<'_, HeaderValue> {
+        self.0.iter()
+    }
+}
+
+/// Displays all values joined by `, `, as in a raw header line.
+impl Display for HeaderValues {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, value) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            Display::fmt(value, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Index<usize> for HeaderValues {
+    type Output = HeaderValue;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl IntoIterator for HeaderValues {
+    type Item = HeaderValue;
+    type IntoIter = std::vec::IntoIter<HeaderValue>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a HeaderValues {
+    type Item = &'a HeaderValue;
+    type IntoIter = std::slice::Iter<'a, HeaderValue>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// A set of HTTP headers.
+#[derive(Debug, Clone, Default)]
+pub struct Headers {
+    headers: HashMap<HeaderName, HeaderValues>,
+}
+
+impl Headers {
+    /// Create a new, empty set of headers.
+    pub fn new() -> Self {
+        Self {
+            headers: HashMap::new(),
+        }
+    }
+
+    /// Insert a header, replacing any existing values for that name.
+    ///
+    /// Returns the previous values, if any were set.
+    pub fn insert(
+        &mut self,
+        name: impl Into<HeaderName>,
+        values: impl ToHeaderValues,
+    ) -> io::Result<Option<HeaderValues>> {
+        let values = values.to_header_values()?;
+        Ok(self.headers.insert(name.into(), values))
+    }
+
+    /// Get a header's values.
+    pub fn get(&self, name: impl Into<HeaderName>) -> Option<&HeaderValues> {
+        self.headers.get(&name.into())
+    }
+
+    /// Get a mutable reference to a header's values.
+    pub fn get_mut(&mut self, name: impl Into<HeaderName>) -> Option<&mut HeaderValues> {
+        self.headers.get_mut(&name.into())
+    }
+
+    /// An iterator visiting all header pairs in arbitrary order.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter(self.headers.iter())
+    }
+
+    /// An iterator visiting all header pairs in arbitrary order, with mutable references to the
+    /// values.
+    pub fn iter_mut(&mut self) -> IterMut<'_> {
+        IterMut(self.headers.iter_mut())
+    }
+}
+
+impl IntoIterator for Headers {
+    type Item = (HeaderName, HeaderValues);
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.headers.into_iter())
+    }
+}
+
+/// An owning iterator over the entries of `Headers`.
+#[derive(Debug)]
+pub struct IntoIter(MapIntoIter<HeaderName, HeaderValues>);
+
+impl Iterator for IntoIter {
+    type Item = (HeaderName, HeaderValues);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/// An iterator over the entries of `Headers`.
+#[derive(Debug)]
+pub struct Iter<'a>(MapIter<'a, HeaderName, HeaderValues>);
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (&'a HeaderName, &'a HeaderValues);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/// A mutable iterator over the entries of `Headers`.
+#[derive(Debug)]
+pub struct IterMut<'a>(MapIterMut<'a, HeaderName, HeaderValues>);
+
+impl<'a> Iterator for IterMut<'a> {
+    type Item = (&'a HeaderName, &'a mut HeaderValues);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/// A trait for converting a type into a list of `HeaderValue`s.
+pub trait ToHeaderValues {
+    /// Convert this type into a `HeaderValues`.
+    fn to_header_values(self) -> io::Result<HeaderValues>;
+}
+
+impl ToHeaderValues for HeaderValue {
+    fn to_header_values(self) -> io::Result<HeaderValues> {
+        Ok(HeaderValues::new(vec![self]))
+    }
+}
+
+impl ToHeaderValues for HeaderValues {
+    fn to_header_values(self) -> io::Result<HeaderValues> {
+        Ok(self)
+    }
+}
+
+impl ToHeaderValues for &str {
+    fn to_header_values(self) -> io::Result<HeaderValues> {
+        Ok(HeaderValues::new(vec![HeaderValue::from(self)]))
+    }
+}
+
+impl ToHeaderValues for String {
+    fn to_header_values(self) -> io::Result<HeaderValues> {
+        Ok(HeaderValues::new(vec![HeaderValue::from(self)]))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn header_lookup_is_case_insensitive() {
+        let mut headers = Headers::new();
+        headers.insert("Content-Type", "text/plain").unwrap();
+
+        assert_eq!(
+            headers.get("content-type").unwrap().last().unwrap().as_str(),
+            "text/plain",
+        );
+        assert_eq!(
+            headers.get("CONTENT-TYPE").unwrap().last().unwrap().as_str(),
+            "text/plain",
+        );
+    }
+
+    #[test]
+    fn differently_cased_names_share_one_entry() {
+        let mut headers = Headers::new();
+        headers.insert("Content-Type", "text/plain").unwrap();
+        let previous = headers.insert("content-type", "application/json").unwrap();
+
+        assert!(previous.is_some());
+        assert_eq!(headers.iter().count(), 1);
+        assert_eq!(
+            headers.get("Content-Type").unwrap().last().unwrap().as_str(),
+            "application/json",
+        );
+    }
+}
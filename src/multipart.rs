@@ -0,0 +1,332 @@
+use async_std::io::{self, BufRead, Read};
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+
+use crate::body::Body;
+use crate::mime::Mime;
+
+type Segment = Box<dyn BufRead + Unpin + Send + 'static>;
+
+enum Part {
+    Text {
+        name: String,
+        value: String,
+    },
+    Reader {
+        name: String,
+        filename: String,
+        mime: Mime,
+        reader: Segment,
+        len: Option<usize>,
+    },
+}
+
+/// A builder for streaming `multipart/form-data` request bodies.
+///
+/// Parts are never buffered in memory as a whole; each part is chained lazily into the final
+/// body so large file uploads stream straight through. Parts are kept as-is until
+/// [`into_body`](Multipart::into_body), so the boundary can be finalized (and, if necessary,
+/// regenerated to avoid colliding with in-memory part data) before any framing bytes are built.
+pub struct Multipart {
+    boundary: String,
+    parts: Vec<Part>,
+}
+
+impl Multipart {
+    /// Create a new, empty multipart body.
+    pub fn new() -> Self {
+        Self {
+            boundary: generate_boundary(),
+            parts: Vec::new(),
+        }
+    }
+
+    /// Add a text field.
+    pub fn add_text(&mut self, name: impl AsRef<str>, value: impl Into<String>) -> &mut Self {
+        self.parts.push(Part::Text {
+            name: name.as_ref().to_owned(),
+            value: value.into(),
+        });
+        self
+    }
+
+    /// Add a file field, streamed from a reader.
+    ///
+    /// `len` is the length of `reader` in bytes, if known. When every part added to this
+    /// `Multipart` has a known length, the resulting body's length is the exact sum; otherwise
+    /// the body streams as chunked. Because `reader` is streamed rather than buffered, its
+    /// contents are not inspected when picking a boundary that can't collide with part data; only
+    /// in-memory parts (`add_text`) are checked.
+    pub fn add_reader(
+        &mut self,
+        name: impl AsRef<str>,
+        filename: impl AsRef<str>,
+        mime: Mime,
+        reader: impl BufRead + Unpin + Send + 'static,
+        len: Option<usize>,
+    ) -> &mut Self {
+        self.parts.push(Part::Reader {
+            name: name.as_ref().to_owned(),
+            filename: filename.as_ref().to_owned(),
+            mime,
+            reader: Box::new(reader),
+            len,
+        });
+        self
+    }
+
+    /// Finalize the multipart body, ready to be set on a `Request`.
+    pub fn into_body(mut self) -> Body {
+        self.avoid_boundary_collisions();
+
+        let mut segments = VecDeque::new();
+        let mut length = Some(0usize);
+        for part in self.parts {
+            match part {
+                Part::Text { name, value } => {
+                    let header = format!(
+                        "--{boundary}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n",
+                        boundary = self.boundary,
+                        name = name,
+                    )
+                    .into_bytes();
+                    push_known(&mut segments, &mut length, header);
+                    push_known(&mut segments, &mut length, value.into_bytes());
+                    push_known(&mut segments, &mut length, b"\r\n".to_vec());
+                }
+                Part::Reader {
+                    name,
+                    filename,
+                    mime,
+                    reader,
+                    len,
+                } => {
+                    let mut header = format!(
+                        "--{boundary}\r\nContent-Disposition: form-data; name=\"{name}\"; filename=\"{filename}\"\r\n",
+                        boundary = self.boundary,
+                        name = name,
+                        filename = filename,
+                    );
+                    header.push_str(&format!("Content-Type: {}\r\n\r\n", mime));
+                    push_known(&mut segments, &mut length, header.into_bytes());
+
+                    segments.push_back(reader);
+                    length = match (length, len) {
+                        (Some(total), Some(len)) => Some(total + len),
+                        _ => None,
+                    };
+
+                    push_known(&mut segments, &mut length, b"\r\n".to_vec());
+                }
+            }
+        }
+        let closing = format!("--{}--\r\n", self.boundary).into_bytes();
+        push_known(&mut segments, &mut length, closing);
+
+        // `generate_boundary` (and `avoid_boundary_collisions`, which only ever swaps in more
+        // output from it) guarantees ASCII alphanumeric characters, which always form a valid
+        // mime boundary parameter without escaping.
+        debug_assert!(
+            self.boundary.bytes().all(|b| b.is_ascii_alphanumeric()),
+            "multipart boundary must stay ASCII alphanumeric to embed in Content-Type unescaped",
+        );
+        let mime: Mime = format!("multipart/form-data; boundary={}", self.boundary)
+            .parse()
+            .expect("ASCII alphanumeric boundary always parses as a valid mime parameter");
+
+        let mut body = Body::from_reader(Chained::new(segments), length);
+        body.set_mime(mime);
+        body
+    }
+
+    /// Regenerate the boundary until it can't be found inside any in-memory part's data.
+    ///
+    /// Parts streamed from a reader (`add_reader`) are not buffered, so they're not inspected
+    /// here; only `add_text` values, which already live in memory, are checked.
+    fn avoid_boundary_collisions(&mut self) {
+        while self.parts.iter().any(|part| match part {
+            Part::Text { value, .. } => value.contains(self.boundary.as_str()),
+            Part::Reader { .. } => false,
+        }) {
+            self.boundary = generate_boundary();
+        }
+    }
+}
+
+fn push_known(segments: &mut VecDeque<Segment>, length: &mut Option<usize>, bytes: Vec<u8>) {
+    *length = length.map(|total| total + bytes.len());
+    segments.push_back(Box::new(io::Cursor::new(bytes)));
+}
+
+impl Default for Multipart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Generate a random boundary token, made up of 32 ASCII alphanumeric characters.
+fn generate_boundary() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect()
+}
+
+/// A `BufRead` that lazily chains together the segments of a multipart body.
+struct Chained {
+    segments: VecDeque<Segment>,
+}
+
+impl Chained {
+    fn new(segments: VecDeque<Segment>) -> Self {
+        Self { segments }
+    }
+}
+
+impl Read for Chained {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        loop {
+            let front = match self.segments.front_mut() {
+                Some(front) => front,
+                None => return Poll::Ready(Ok(0)),
+            };
+            match Pin::new(front).poll_read(cx, buf) {
+                Poll::Ready(Ok(0)) => {
+                    self.segments.pop_front();
+                    continue;
+                }
+                Poll::Ready(Ok(n)) => return Poll::Ready(Ok(n)),
+                Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl BufRead for Chained {
+    fn poll_fill_buf(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&'_ [u8]>> {
+        loop {
+            let front = match self.segments.front_mut() {
+                Some(front) => front,
+                None => return Poll::Ready(Ok(&[])),
+            };
+            let is_empty = match Pin::new(front).poll_fill_buf(cx) {
+                Poll::Ready(Ok(buf)) => buf.is_empty(),
+                Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                Poll::Pending => return Poll::Pending,
+            };
+            if is_empty {
+                self.segments.pop_front();
+            } else {
+                break;
+            }
+        }
+        Pin::new(self.segments.front_mut().unwrap()).poll_fill_buf(cx)
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amt: usize) {
+        if let Some(front) = self.segments.front_mut() {
+            Pin::new(front).consume(amt)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use async_std::io::ReadExt;
+    use async_std::task::block_on;
+
+    fn read_all(body: Body) -> Vec<u8> {
+        let mut body = body;
+        let mut buf = Vec::new();
+        block_on(body.read_to_end(&mut buf)).unwrap();
+        buf
+    }
+
+    #[test]
+    fn wire_format_for_text_fields() {
+        let mut multipart = Multipart::new();
+        multipart.add_text("name", "Chashu");
+        multipart.add_text("species", "cat");
+        // Fix the boundary so the expected bytes are exact.
+        multipart.boundary = "BOUNDARY".into();
+
+        let body = multipart.into_body();
+        let length = body.len();
+        let bytes = read_all(body);
+
+        let expected = b"\
+--BOUNDARY\r\n\
+Content-Disposition: form-data; name=\"name\"\r\n\
+\r\n\
+Chashu\r\n\
+--BOUNDARY\r\n\
+Content-Disposition: form-data; name=\"species\"\r\n\
+\r\n\
+cat\r\n\
+--BOUNDARY--\r\n";
+
+        assert_eq!(bytes, expected);
+        assert_eq!(length, Some(expected.len()));
+    }
+
+    #[test]
+    fn unknown_reader_length_makes_body_length_none() {
+        let mut multipart = Multipart::new();
+        multipart.add_text("name", "Chashu");
+        multipart.add_reader(
+            "file",
+            "chashu.txt",
+            crate::mime::BYTE_STREAM,
+            io::Cursor::new(b"hello".to_vec()),
+            None,
+        );
+
+        let body = multipart.into_body();
+        assert_eq!(body.len(), None);
+    }
+
+    #[test]
+    fn known_reader_length_sums_exactly() {
+        let mut multipart = Multipart::new();
+        multipart.boundary = "BOUNDARY".into();
+        multipart.add_reader(
+            "file",
+            "chashu.txt",
+            crate::mime::BYTE_STREAM,
+            io::Cursor::new(b"hello".to_vec()),
+            Some(5),
+        );
+
+        let body = multipart.into_body();
+        let length = body.len();
+        let bytes = read_all(body);
+        assert_eq!(length, Some(bytes.len()));
+    }
+
+    #[test]
+    fn regenerates_boundary_on_collision() {
+        let mut multipart = Multipart::new();
+        multipart.boundary = "BOUNDARY".into();
+        multipart.add_text("name", "contains BOUNDARY in the value");
+
+        multipart.avoid_boundary_collisions();
+
+        assert_ne!(multipart.boundary, "BOUNDARY");
+        assert!(multipart.parts.iter().all(|part| match part {
+            Part::Text { value, .. } => !value.contains(&multipart.boundary),
+            Part::Reader { .. } => true,
+        }));
+    }
+}
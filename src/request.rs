@@ -1,15 +1,16 @@
 use async_std::io::{self, BufRead, Read};
 
-use std::borrow::Borrow;
 use std::fmt::{self, Debug};
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
-use crate::headers::{self, HeaderName, HeaderValue, Headers, ToHeaderValues};
-use crate::mime::{self, Mime};
+use crate::body::Body;
+use crate::headers::{self, HeaderName, HeaderValue, HeaderValues, Headers, ToHeaderValues};
+use crate::mime::Mime;
 use crate::{Method, Url};
 
-type BodyReader = dyn BufRead + Unpin + Send + 'static;
+#[cfg(feature = "serde")]
+use serde::Serialize;
 
 pin_project_lite::pin_project! {
     /// An HTTP request.
@@ -18,8 +19,7 @@ pin_project_lite::pin_project! {
         url: Url,
         headers: Headers,
         #[pin]
-        body_reader: Box<BodyReader>,
-        length: Option<usize>,
+        body: Body,
     }
 }
 
@@ -30,8 +30,7 @@ impl Request {
             method,
             url,
             headers: Headers::new(),
-            body_reader: Box::new(io::empty()),
-            length: Some(0),
+            body: Body::empty(),
         }
     }
 
@@ -50,19 +49,36 @@ impl Request {
         &self.headers
     }
 
-    /// Get the body
-    pub fn body_reader(&self) -> &Box<BodyReader> {
-        &self.body_reader
+    /// Get the body.
+    pub fn body(&self) -> &Body {
+        &self.body
     }
 
-    /// Consume self and get body
-    pub fn into_body_reader(self) -> Box<BodyReader> {
-        self.body_reader
+    /// Set the body, applying the body's MIME to the `content-type` header.
+    pub fn set_body(&mut self, body: impl Into<Body>) -> io::Result<()> {
+        self.body = body.into();
+        let mime = self.body.mime().clone();
+        self.set_mime(mime)?;
+        Ok(())
+    }
+
+    /// Take the body, leaving an empty body in its place.
+    pub fn take_body(&mut self) -> Body {
+        std::mem::replace(&mut self.body, Body::empty())
+    }
+
+    /// Swap in a new body, returning the previous one.
+    pub fn replace_body(&mut self, body: impl Into<Body>) -> io::Result<Body> {
+        let mut body = body.into();
+        std::mem::swap(&mut self.body, &mut body);
+        let mime = self.body.mime().clone();
+        self.set_mime(mime)?;
+        Ok(body)
     }
 
     /// Set the body reader.
-    pub fn set_body_reader(&mut self, body: impl BufRead + Unpin + Send + 'static) {
-        self.body_reader = Box::new(body);
+    pub fn set_body_reader(&mut self, reader: impl BufRead + Unpin + Send + 'static) {
+        self.body = Body::from_reader(reader, None);
     }
 
     /// Set the body as a string.
@@ -71,11 +87,7 @@ impl Request {
     ///
     /// The encoding is set to `text/plain; charset=utf-8`.
     pub fn set_body_string(&mut self, string: String) -> io::Result<()> {
-        self.length = Some(string.len());
-        let reader = io::Cursor::new(string.into_bytes());
-        self.set_body_reader(reader);
-        self.set_mime(mime::PLAIN)?;
-        Ok(())
+        self.set_body(Body::from_string(string))
     }
 
     /// Pass bytes as the request body.
@@ -84,42 +96,53 @@ impl Request {
     ///
     /// The encoding is set to `application/octet-stream`.
     pub fn set_body_bytes(&mut self, bytes: impl AsRef<[u8]>) -> io::Result<()> {
-        let bytes = bytes.as_ref().to_owned();
-        self.length = Some(bytes.len());
-        let reader = io::Cursor::new(bytes);
-        self.set_body_reader(reader);
-        self.set_mime(mime::BYTE_STREAM)?;
-        Ok(())
+        self.set_body(Body::from_bytes(bytes))
+    }
+
+    /// Set the body to the JSON-serialized value.
+    ///
+    /// # Mime
+    ///
+    /// The encoding is set to `application/json`.
+    #[cfg(feature = "serde")]
+    pub fn set_body_json<T: Serialize>(&mut self, value: &T) -> io::Result<()> {
+        self.set_body(Body::from_json(value)?)
+    }
+
+    /// Set the body to the form-urlencoded value.
+    ///
+    /// # Mime
+    ///
+    /// The encoding is set to `application/x-www-form-urlencoded`.
+    #[cfg(feature = "serde")]
+    pub fn set_body_form<T: Serialize>(&mut self, value: &T) -> io::Result<()> {
+        self.set_body(Body::from_form(value)?)
     }
 
     /// Get an HTTP header.
-    pub fn header(&self, name: &HeaderName) -> Option<&Vec<HeaderValue>> {
-        self.headers.get(name.borrow())
+    pub fn header(&self, name: impl Into<HeaderName>) -> Option<&HeaderValues> {
+        self.headers.get(name)
     }
 
     /// Get a mutable reference to a header.
-    pub fn get_mut(&mut self, name: &HeaderName) -> Option<&mut Vec<HeaderValue>> {
+    pub fn get_mut(&mut self, name: impl Into<HeaderName>) -> Option<&mut HeaderValues> {
         self.headers.get_mut(name)
     }
 
     /// Set an HTTP header.
     pub fn set_header(
         &mut self,
-        name: HeaderName,
+        name: impl Into<HeaderName>,
         values: impl ToHeaderValues,
-    ) -> io::Result<Option<Vec<HeaderValue>>> {
+    ) -> io::Result<Option<HeaderValues>> {
         self.headers.insert(name, values)
     }
 
     /// Set the response MIME.
     // TODO: return a parsed MIME
-    pub fn set_mime(&mut self, mime: Mime) -> io::Result<Option<Vec<HeaderValue>>> {
-        let header = HeaderName {
-            string: String::new(),
-            static_str: Some("content-type"),
-        };
+    pub fn set_mime(&mut self, mime: Mime) -> io::Result<Option<HeaderValues>> {
         let value: HeaderValue = mime.into();
-        self.set_header(header, value)
+        self.set_header("content-type", value)
     }
 
     /// Get the length of the body stream, if it has been set.
@@ -128,13 +151,7 @@ impl Request {
     /// buffer. Consumers of this API should check this value to decide whether to use `Chunked`
     /// encoding, or set the response length.
     pub fn len(&self) -> Option<usize> {
-        self.length
-    }
-
-    /// Set the length of the body stream
-    pub fn set_len(mut self, len: usize) -> Self {
-        self.length = Some(len);
-        self
+        self.body.len()
     }
 
     /// An iterator visiting all header pairs in arbitrary order.
@@ -167,7 +184,7 @@ impl Read for Request {
         cx: &mut Context<'_>,
         buf: &mut [u8],
     ) -> Poll<io::Result<usize>> {
-        Pin::new(&mut self.body_reader).poll_read(cx, buf)
+        Pin::new(&mut self.body).poll_read(cx, buf)
     }
 }
 
@@ -175,11 +192,11 @@ impl BufRead for Request {
     #[allow(missing_doc_code_examples)]
     fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&'_ [u8]>> {
         let this = self.project();
-        this.body_reader.poll_fill_buf(cx)
+        this.body.poll_fill_buf(cx)
     }
 
     fn consume(mut self: Pin<&mut Self>, amt: usize) {
-        Pin::new(&mut self.body_reader).consume(amt)
+        Pin::new(&mut self.body).consume(amt)
     }
 }
 
@@ -196,7 +213,7 @@ impl AsMut<Headers> for Request {
 }
 
 impl IntoIterator for Request {
-    type Item = (HeaderName, Vec<HeaderValue>);
+    type Item = (HeaderName, HeaderValues);
     type IntoIter = headers::IntoIter;
 
     /// Returns a iterator of references over the remaining items.
@@ -207,7 +224,7 @@ impl IntoIterator for Request {
 }
 
 impl<'a> IntoIterator for &'a Request {
-    type Item = (&'a HeaderName, &'a Vec<HeaderValue>);
+    type Item = (&'a HeaderName, &'a HeaderValues);
     type IntoIter = headers::Iter<'a>;
 
     #[inline]
@@ -217,7 +234,7 @@ impl<'a> IntoIterator for &'a Request {
 }
 
 impl<'a> IntoIterator for &'a mut Request {
-    type Item = (&'a HeaderName, &'a mut Vec<HeaderValue>);
+    type Item = (&'a HeaderName, &'a mut HeaderValues);
     type IntoIter = headers::IterMut<'a>;
 
     #[inline]
@@ -0,0 +1,84 @@
+use async_std::io;
+
+use crate::body::Body;
+use crate::headers::{HeaderName, ToHeaderValues};
+use crate::mime::Mime;
+use crate::{Method, Request, Url};
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// A builder for constructing `Request`s one piece at a time.
+pub struct RequestBuilder {
+    request: Request,
+    error: Option<io::Error>,
+}
+
+impl RequestBuilder {
+    /// Create a new `RequestBuilder` for the given method and url.
+    pub fn new(method: Method, url: Url) -> Self {
+        Self {
+            request: Request::new(method, url),
+            error: None,
+        }
+    }
+
+    /// Set an HTTP header.
+    pub fn header(mut self, name: impl Into<HeaderName>, values: impl ToHeaderValues) -> Self {
+        if let Err(err) = self.request.set_header(name, values) {
+            self.error = self.error.or(Some(err));
+        }
+        self
+    }
+
+    /// Set the content type.
+    pub fn content_type(mut self, mime: Mime) -> Self {
+        if let Err(err) = self.request.set_mime(mime) {
+            self.error = self.error.or(Some(err));
+        }
+        self
+    }
+
+    /// Set the body.
+    pub fn body(mut self, body: impl Into<Body>) -> Self {
+        if let Err(err) = self.request.set_body(body) {
+            self.error = self.error.or(Some(err));
+        }
+        self
+    }
+
+    /// Set the body to a JSON-serialized value.
+    #[cfg(feature = "serde")]
+    pub fn body_json<T: Serialize>(mut self, value: &T) -> Self {
+        if let Err(err) = self.request.set_body_json(value) {
+            self.error = self.error.or(Some(err));
+        }
+        self
+    }
+
+    /// Set the body to a string.
+    pub fn body_string(mut self, string: String) -> Self {
+        if let Err(err) = self.request.set_body_string(string) {
+            self.error = self.error.or(Some(err));
+        }
+        self
+    }
+
+    /// Finish building the `Request`.
+    ///
+    /// Returns the first error raised by a prior call to `header`, `content_type`, or a body
+    /// setter, if any.
+    pub fn build(self) -> io::Result<Request> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(self.request),
+        }
+    }
+}
+
+impl Request {
+    /// Create a `RequestBuilder` for the given method and url.
+    pub fn builder(method: Method, url: Url) -> RequestBuilder {
+        RequestBuilder::new(method, url)
+    }
+}
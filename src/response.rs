@@ -0,0 +1,231 @@
+use async_std::io::{self, BufRead, Read};
+
+use std::fmt::{self, Debug};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::body::Body;
+use crate::headers::{self, HeaderName, HeaderValue, HeaderValues, Headers, ToHeaderValues};
+use crate::mime::Mime;
+use crate::{StatusCode, Version};
+
+pin_project_lite::pin_project! {
+    /// An HTTP response.
+    pub struct Response {
+        status: StatusCode,
+        version: Option<Version>,
+        headers: Headers,
+        #[pin]
+        body: Body,
+    }
+}
+
+impl Response {
+    /// Create a new response.
+    pub fn new(status: StatusCode) -> Self {
+        Self {
+            status,
+            version: None,
+            headers: Headers::new(),
+            body: Body::empty(),
+        }
+    }
+
+    /// Get the status code.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Set the status code.
+    pub fn set_status(&mut self, status: StatusCode) {
+        self.status = status;
+    }
+
+    /// Get the HTTP version, if one has been set.
+    pub fn version(&self) -> Option<Version> {
+        self.version
+    }
+
+    /// Set the HTTP version.
+    pub fn set_version(&mut self, version: Option<Version>) {
+        self.version = version;
+    }
+
+    /// Get the headers
+    pub fn headers(&self) -> &Headers {
+        &self.headers
+    }
+
+    /// Get the body.
+    pub fn body(&self) -> &Body {
+        &self.body
+    }
+
+    /// Set the body, applying the body's MIME to the `content-type` header.
+    pub fn set_body(&mut self, body: impl Into<Body>) -> io::Result<()> {
+        self.body = body.into();
+        let mime = self.body.mime().clone();
+        self.set_mime(mime)?;
+        Ok(())
+    }
+
+    /// Take the body, leaving an empty body in its place.
+    pub fn take_body(&mut self) -> Body {
+        std::mem::replace(&mut self.body, Body::empty())
+    }
+
+    /// Swap in a new body, returning the previous one.
+    pub fn replace_body(&mut self, body: impl Into<Body>) -> io::Result<Body> {
+        let mut body = body.into();
+        std::mem::swap(&mut self.body, &mut body);
+        let mime = self.body.mime().clone();
+        self.set_mime(mime)?;
+        Ok(body)
+    }
+
+    /// Set the body reader.
+    pub fn set_body_reader(&mut self, reader: impl BufRead + Unpin + Send + 'static) {
+        self.body = Body::from_reader(reader, None);
+    }
+
+    /// Set the body as a string.
+    ///
+    /// # Mime
+    ///
+    /// The encoding is set to `text/plain; charset=utf-8`.
+    pub fn set_body_string(&mut self, string: String) -> io::Result<()> {
+        self.set_body(Body::from_string(string))
+    }
+
+    /// Pass bytes as the response body.
+    ///
+    /// # Mime
+    ///
+    /// The encoding is set to `application/octet-stream`.
+    pub fn set_body_bytes(&mut self, bytes: impl AsRef<[u8]>) -> io::Result<()> {
+        self.set_body(Body::from_bytes(bytes))
+    }
+
+    /// Get an HTTP header.
+    pub fn header(&self, name: impl Into<HeaderName>) -> Option<&HeaderValues> {
+        self.headers.get(name)
+    }
+
+    /// Get a mutable reference to a header.
+    pub fn get_mut(&mut self, name: impl Into<HeaderName>) -> Option<&mut HeaderValues> {
+        self.headers.get_mut(name)
+    }
+
+    /// Set an HTTP header.
+    pub fn set_header(
+        &mut self,
+        name: impl Into<HeaderName>,
+        values: impl ToHeaderValues,
+    ) -> io::Result<Option<HeaderValues>> {
+        self.headers.insert(name, values)
+    }
+
+    /// Set the response MIME.
+    // TODO: return a parsed MIME
+    pub fn set_mime(&mut self, mime: Mime) -> io::Result<Option<HeaderValues>> {
+        let value: HeaderValue = mime.into();
+        self.set_header("content-type", value)
+    }
+
+    /// Get the length of the body stream, if it has been set.
+    ///
+    /// This value is set when passing a fixed-size object into as the body. E.g. a string, or a
+    /// buffer. Consumers of this API should check this value to decide whether to use `Chunked`
+    /// encoding, or set the response length.
+    pub fn len(&self) -> Option<usize> {
+        self.body.len()
+    }
+
+    /// An iterator visiting all header pairs in arbitrary order.
+    pub fn iter<'a>(&'a self) -> headers::Iter<'a> {
+        self.headers.iter()
+    }
+
+    /// An iterator visiting all header pairs in arbitrary order, with mutable references to the
+    /// values.
+    pub fn iter_mut<'a>(&'a mut self) -> headers::IterMut<'a> {
+        self.headers.iter_mut()
+    }
+}
+
+impl Debug for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Response")
+            .field("status", &self.status)
+            .field("version", &self.version)
+            .field("headers", &self.headers)
+            .field("body", &"<hidden>")
+            .finish()
+    }
+}
+
+impl Read for Response {
+    #[allow(missing_doc_code_examples)]
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.body).poll_read(cx, buf)
+    }
+}
+
+impl BufRead for Response {
+    #[allow(missing_doc_code_examples)]
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&'_ [u8]>> {
+        let this = self.project();
+        this.body.poll_fill_buf(cx)
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amt: usize) {
+        Pin::new(&mut self.body).consume(amt)
+    }
+}
+
+impl AsRef<Headers> for Response {
+    fn as_ref(&self) -> &Headers {
+        &self.headers
+    }
+}
+
+impl AsMut<Headers> for Response {
+    fn as_mut(&mut self) -> &mut Headers {
+        &mut self.headers
+    }
+}
+
+impl IntoIterator for Response {
+    type Item = (HeaderName, HeaderValues);
+    type IntoIter = headers::IntoIter;
+
+    /// Returns a iterator of references over the remaining items.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.headers.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Response {
+    type Item = (&'a HeaderName, &'a HeaderValues);
+    type IntoIter = headers::Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.headers.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Response {
+    type Item = (&'a HeaderName, &'a mut HeaderValues);
+    type IntoIter = headers::IterMut<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.headers.iter_mut()
+    }
+}